@@ -1,4 +1,6 @@
-/// dimensions given in the requirements
+use std::fmt;
+
+/// The default board dimensions `solve_report` and `auto_place` play on.
 const GRID_WIDTH: usize = 10;
 const GRID_HEIGHT: usize = 100;
 
@@ -9,16 +11,70 @@ struct Placement([(usize, usize); 4]);
 
 /// A tetris block
 trait Tetromino {
-    /// Returns the width of the tetromino.
+    /// Returns the width of the tetromino in its unrotated (rotation 0)
+    /// orientation.
     fn width() -> usize;
 
-    /// Returns the height of the tetromino.
+    /// Returns the height of the tetromino in its unrotated (rotation 0)
+    /// orientation.
     fn height() -> usize;
 
+    /// Returns the number of distinct rotation states the shape has, with
+    /// states that look identical after rotating collapsed (e.g. 1 for Q,
+    /// 2 for I/S/Z, 4 for T/L/J). `rot` values are taken modulo this.
+    fn rotations() -> u8 {
+        4
+    }
+
+    /// Returns the local (lx, ly) cells occupied by the shape at rotation
+    /// 0, relative to the smallest rectangular box that can contain it.
+    fn cells() -> [(usize, usize); 4];
+
     /// Returns the placement of a tetromino shape from a given starting
-    /// point. The starting point is the bottom-left most square occupied
-    /// by the smallest rectangular box that can contain the shape.
-    fn placement_at(at: (usize, usize)) -> Result<Placement, &'static str>;
+    /// point, rotated 90 degrees clockwise `rot` times (mod `rotations()`).
+    /// The starting point is the bottom-left most square occupied by the
+    /// smallest rectangular box that can contain the rotated shape.
+    /// `bounds` is the (width, height) of the grid the shape is placed on.
+    fn placement_at_rotated(
+        at: (usize, usize),
+        rot: u8,
+        bounds: (usize, usize),
+    ) -> Result<Placement, &'static str> {
+        let (w, h, cells) = Self::rotated_shape(rot % Self::rotations());
+
+        if w > bounds.0 || h > bounds.1 || at.0 > bounds.0 - w || at.1 > bounds.1 - h {
+            Err("out of bound")
+        } else {
+            let mut squares = [(0, 0); 4];
+            for (i, (lx, ly)) in cells.iter().enumerate() {
+                squares[i] = (at.0 + lx, at.1 + ly);
+            }
+            Ok(Placement(squares))
+        }
+    }
+
+    /// Rotates `cells()` 90 degrees clockwise `rot` times, mapping each
+    /// local cell `(lx, ly)` to `(ly, w - 1 - lx)` within the shape's
+    /// current bounding box. Since the bounding box is always the smallest
+    /// one containing the shape, the rotated cells are already normalized
+    /// against their new (swapped) bounding box. Returns the resulting
+    /// (width, height, cells).
+    fn rotated_shape(rot: u8) -> (usize, usize, [(usize, usize); 4]) {
+        let mut cells = Self::cells();
+        let mut w = Self::width();
+        let mut h = Self::height();
+
+        for _ in 0..rot {
+            let mut rotated = [(0, 0); 4];
+            for (i, (lx, ly)) in cells.iter().enumerate() {
+                rotated[i] = (*ly, w - 1 - lx);
+            }
+            cells = rotated;
+            std::mem::swap(&mut w, &mut h);
+        }
+
+        (w, h, cells)
+    }
 }
 
 struct Q;
@@ -32,16 +88,17 @@ impl Tetromino for Q {
         2
     }
 
-    /// The placement layout for Q is
+    // O-pieces look the same after any rotation.
+    fn rotations() -> u8 {
+        1
+    }
+
+    /// The shape layout for Q is
     ///
     /// 3 4
     /// 1 2
-    fn placement_at((x, y): (usize, usize)) -> Result<Placement, &'static str> {
-        if x > (GRID_WIDTH - Self::width()) || y > (GRID_HEIGHT - Self::height()) {
-            Err("out of bound")
-        } else {
-            Ok(Placement([(x, y), (x + 1, y), (x, y + 1), (x + 1, y + 1)]))
-        }
+    fn cells() -> [(usize, usize); 4] {
+        [(0, 0), (1, 0), (0, 1), (1, 1)]
     }
 }
 
@@ -56,21 +113,17 @@ impl Tetromino for Z {
         2
     }
 
-    /// The placement layout for Z is
+    // Rotating Z by 180 degrees reproduces the same shape.
+    fn rotations() -> u8 {
+        2
+    }
+
+    /// The shape layout for Z is
     ///
     /// 3 4
     ///   1 2
-    fn placement_at((x, y): (usize, usize)) -> Result<Placement, &'static str> {
-        if x > (GRID_WIDTH - Self::width()) || y > (GRID_HEIGHT - Self::height()) {
-            Err("out of bound")
-        } else {
-            Ok(Placement([
-                (x + 1, y),
-                (x + 2, y),
-                (x, y + 1),
-                (x + 1, y + 1),
-            ]))
-        }
+    fn cells() -> [(usize, usize); 4] {
+        [(1, 0), (2, 0), (0, 1), (1, 1)]
     }
 }
 
@@ -85,21 +138,17 @@ impl Tetromino for S {
         2
     }
 
-    /// The placement layout for S is
+    // Rotating S by 180 degrees reproduces the same shape.
+    fn rotations() -> u8 {
+        2
+    }
+
+    /// The shape layout for S is
     ///
     ///   3 4
     /// 1 2
-    fn placement_at((x, y): (usize, usize)) -> Result<Placement, &'static str> {
-        if x > (GRID_WIDTH - Self::width()) || y > (GRID_HEIGHT - Self::height()) {
-            Err("out of bound")
-        } else {
-            Ok(Placement([
-                (x, y),
-                (x + 1, y),
-                (x + 1, y + 1),
-                (x + 2, y + 1),
-            ]))
-        }
+    fn cells() -> [(usize, usize); 4] {
+        [(0, 0), (1, 0), (1, 1), (2, 1)]
     }
 }
 
@@ -114,21 +163,12 @@ impl Tetromino for T {
         2
     }
 
-    /// The placement layout for T is
+    /// The shape layout for T is
     ///
     /// 2 3 4
     ///   1
-    fn placement_at((x, y): (usize, usize)) -> Result<Placement, &'static str> {
-        if x > (GRID_WIDTH - Self::width()) || y > (GRID_HEIGHT - Self::height()) {
-            Err("out of bound")
-        } else {
-            Ok(Placement([
-                (x + 1, y),
-                (x, y + 1),
-                (x + 1, y + 1),
-                (x + 2, y + 1),
-            ]))
-        }
+    fn cells() -> [(usize, usize); 4] {
+        [(1, 0), (0, 1), (1, 1), (2, 1)]
     }
 }
 
@@ -143,15 +183,16 @@ impl Tetromino for I {
         1
     }
 
-    /// The placement layout for I is
+    // Rotating I by 180 degrees reproduces the same shape.
+    fn rotations() -> u8 {
+        2
+    }
+
+    /// The shape layout for I is
     ///
     /// 1 2 3 4
-    fn placement_at((x, y): (usize, usize)) -> Result<Placement, &'static str> {
-        if x > (GRID_WIDTH - Self::width()) || y > (GRID_HEIGHT - Self::height()) {
-            Err("out of bound")
-        } else {
-            Ok(Placement([(x, y), (x + 1, y), (x + 2, y), (x + 3, y)]))
-        }
+    fn cells() -> [(usize, usize); 4] {
+        [(0, 0), (1, 0), (2, 0), (3, 0)]
     }
 }
 
@@ -166,17 +207,13 @@ impl Tetromino for L {
         3
     }
 
-    /// The placement layout for L is
+    /// The shape layout for L is
     ///
     /// 4
     /// 3
     /// 1 2
-    fn placement_at((x, y): (usize, usize)) -> Result<Placement, &'static str> {
-        if x > (GRID_WIDTH - Self::width()) || y > (GRID_HEIGHT - Self::height()) {
-            Err("out of bound")
-        } else {
-            Ok(Placement([(x, y), (x + 1, y), (x, y + 1), (x, y + 2)]))
-        }
+    fn cells() -> [(usize, usize); 4] {
+        [(0, 0), (1, 0), (0, 1), (0, 2)]
     }
 }
 
@@ -191,61 +228,84 @@ impl Tetromino for J {
         3
     }
 
-    /// The placement layout for J is
+    /// The shape layout for J is
     ///
     ///   4
     ///   3
     /// 1 2
-    fn placement_at((x, y): (usize, usize)) -> Result<Placement, &'static str> {
-        if x > (GRID_WIDTH - Self::width()) || y > (GRID_HEIGHT - Self::height()) {
-            Err("out of bound")
-        } else {
-            Ok(Placement([
-                (x, y),
-                (x + 1, y),
-                (x + 1, y + 1),
-                (x + 1, y + 2),
-            ]))
-        }
+    fn cells() -> [(usize, usize); 4] {
+        [(0, 0), (1, 0), (1, 1), (1, 2)]
     }
 }
 
-/// The grid is represented by a 10x100 2 dimensional array of booleans.
-/// A `false` indicates that the square at the coordinates is empty.
+/// The grid is represented by one `u128` bitmask per row, bit `x` of row
+/// `y` set means the square at `(x, y)` is occupied. This keeps
+/// `is_row_filled`/`can_place` to single integer comparisons instead of
+/// scanning a `Vec<bool>` per row, and supports boards up to 128 columns
+/// wide.
+#[derive(Clone)]
 struct Grid {
-    squares: Vec<Vec<bool>>,
+    width: usize,
+    height: usize,
+    full_mask: u128,
+    rows: Vec<u128>,
     first_blank: usize,
 }
 
 impl Grid {
-    pub fn new() -> Self {
+    pub fn new(width: usize, height: usize) -> Self {
+        assert!(width <= 128, "width must fit in a u128 row bitmask");
+
+        let full_mask = if width == 128 {
+            u128::MAX
+        } else {
+            (1u128 << width) - 1
+        };
+
         Grid {
-            squares: vec![vec![false; 10]; 100],
+            width,
+            height,
+            full_mask,
+            rows: vec![0; height],
             first_blank: 0,
         }
     }
 
+    /// Returns the width of the grid.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the height of the grid.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
     /// Returns whether a row is completely filled.
     fn is_row_filled(&self, y: usize) -> bool {
-        self.squares[y].iter().all(|x| *x)
+        self.rows[y] == self.full_mask
     }
 
     /// Returns whether a placement can be placed on the grid, i.e. all the
     /// squares make up the placement are empty.
     fn can_place(&self, p: &Placement) -> bool {
-        p.0.iter().all(|(x, y)| !self.squares[*y][*x])
+        p.0.iter().all(|(x, y)| self.rows[*y] & (1u128 << x) == 0)
     }
 
-    /// Places the tetromino on the grid.
-    pub fn place<T: Tetromino>(&mut self, column: usize) -> Result<(), &'static str> {
+    /// Places the tetromino on the grid, rotated `rot` times (see
+    /// `Tetromino::placement_at_rotated`). Returns how many rows this
+    /// placement cleared.
+    pub fn place<T: Tetromino>(&mut self, column: usize, rot: u8) -> Result<usize, &'static str> {
+        let bounds = (self.width(), self.height());
+
         // the first blank row is the obvious candidate. so we will start
         // with a placement there.
-        let mut placement = T::placement_at((column, self.first_blank))?;
+        let mut placement = T::placement_at_rotated((column, self.first_blank), rot, bounds)?;
 
         // but let's see if we can do better. iterate partially filled rows
         // from top to bottom to check if the tetromino can fit.
         for row in (0..self.first_blank).rev() {
-            let p = T::placement_at((column, row))?;
+            let p = T::placement_at_rotated((column, row), rot, bounds)?;
             if self.can_place(&p) {
                 placement = p;
             } else {
@@ -258,63 +318,267 @@ impl Grid {
         placement
             .0
             .iter()
-            .for_each(|(x, y)| self.squares[*y][*x] = true);
+            .for_each(|(x, y)| self.rows[*y] |= 1u128 << x);
 
-        // the top of the new placement might be the new height. and the
-        // placement is ordered so that the last square has the greatest
-        // height
-        let top = placement.0[3].1;
+        // the top and bottom of the new placement, found from the squares
+        // themselves since rotation means the shape no longer spans a
+        // fixed number of rows in a fixed order.
+        let top = placement.0.iter().map(|(_, y)| *y).max().unwrap();
+        let bottom = placement.0.iter().map(|(_, y)| *y).min().unwrap();
 
         // the first blank row is the greater of the current height and
         // the new height.
         self.first_blank = std::cmp::max(self.first_blank, top + 1);
 
         // remove the fully filled rows, the max # of rows that can be
-        // filled equals to the height of the tetromino.
-        for i in 0..T::height() {
+        // filled equals to the number of rows the placement spans.
+        let mut cleared = 0;
+        for i in 0..(top - bottom + 1) {
             let y = top - i;
             if self.is_row_filled(y) {
-                self.squares.remove(y);
-                self.squares.push(vec![false; 10]);
+                self.rows.remove(y);
+                self.rows.push(0);
                 self.first_blank -= 1;
+                cleared += 1;
             }
         }
 
-        Ok(())
+        Ok(cleared)
     }
 
     /// Returns the height of the remaining blocks.
-    pub fn height(&self) -> usize {
+    pub fn stack_height(&self) -> usize {
         self.first_blank
     }
+
+    /// Returns every `(column, rotation)` pair `T` can legally be dropped
+    /// at, i.e. every placement starting from the first blank row that
+    /// fits within the grid's bounds. Where it actually lands (and
+    /// whether it clears lines) is still decided by `place`.
+    pub fn legal_drops<T: Tetromino>(&self) -> Vec<(usize, u8)> {
+        let bounds = (self.width(), self.height());
+        let mut drops = Vec::new();
+
+        for rot in 0..T::rotations() {
+            for column in 0..self.width() {
+                if T::placement_at_rotated((column, self.first_blank), rot, bounds).is_ok() {
+                    drops.push((column, rot));
+                }
+            }
+        }
+
+        drops
+    }
+
+    /// Scores the current board for the auto-placement search: higher is
+    /// better. Penalizes aggregate column height, enclosed holes (empty
+    /// squares with a filled square somewhere above them) and bumpiness
+    /// (height differences between adjacent columns). Completed lines are
+    /// rewarded implicitly, since `place` has already cleared them by the
+    /// time `score` runs, lowering the height term.
+    pub fn score(&self) -> i64 {
+        let mut column_heights = vec![0i64; self.width()];
+        let mut holes = 0i64;
+
+        for (x, height) in column_heights.iter_mut().enumerate() {
+            let mut seen_block = false;
+            for y in (0..self.height()).rev() {
+                let occupied = self.rows[y] & (1u128 << x) != 0;
+                if occupied {
+                    seen_block = true;
+                    if *height == 0 {
+                        *height = y as i64 + 1;
+                    }
+                } else if seen_block {
+                    holes += 1;
+                }
+            }
+        }
+
+        let aggregate_height: i64 = column_heights.iter().sum();
+        let bumpiness: i64 = column_heights
+            .windows(2)
+            .map(|pair| (pair[0] - pair[1]).abs())
+            .sum();
+
+        -aggregate_height - 4 * holes - bumpiness
+    }
 }
 
-fn solve(input: &str) -> Result<usize, &'static str> {
-    let mut grid = Grid::new();
+/// Renders the grid as an ASCII well: occupied squares as `#`, empty
+/// squares as a space, from the top non-empty row down to row 0, with a
+/// floor underneath.
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in (0..self.first_blank).rev() {
+            for x in 0..self.width() {
+                let occupied = self.rows[y] & (1u128 << x) != 0;
+                write!(f, "{}", if occupied { '#' } else { ' ' })?;
+            }
+            writeln!(f)?;
+        }
+
+        write!(f, "└{}┘", "─".repeat(self.width()))
+    }
+}
+
+/// The outcome of replaying a drop sequence through `solve_report`, useful
+/// for comparing two sequences by more than just their final height (e.g.
+/// total lines cleared or peak height reached along the way).
+struct SolveReport {
+    final_height: usize,
+    lines_cleared: usize,
+    max_height_seen: usize,
+    pieces_placed: usize,
+}
+
+/// Whether board/report printing is turned on, gated behind an env var
+/// rather than a CLI flag since neither `solve_report` nor `main`'s input
+/// loop ever sees argv.
+fn verbose_mode() -> bool {
+    std::env::var("TETRIS_VERBOSE").is_ok()
+}
+
+fn solve_report(input: &str) -> Result<SolveReport, &'static str> {
+    let mut grid = Grid::new(GRID_WIDTH, GRID_HEIGHT);
+    let verbose = verbose_mode();
+
+    let mut lines_cleared = 0;
+    let mut max_height_seen = 0;
+    let mut pieces_placed = 0;
 
     for entry in input.split(',') {
         let mut chars = entry.trim().chars();
         let tetromino = chars.next().ok_or("missing tetromino")?;
         let column = chars
             .next()
-            .map(|c| c.to_digit(10))
-            .flatten()
+            .and_then(|c| c.to_digit(10))
             .map(|i| i as usize)
             .ok_or("bad column")?;
 
-        match tetromino {
-            'Q' => grid.place::<Q>(column),
-            'Z' => grid.place::<Z>(column),
-            'S' => grid.place::<S>(column),
-            'T' => grid.place::<T>(column),
-            'I' => grid.place::<I>(column),
-            'L' => grid.place::<L>(column),
-            'J' => grid.place::<J>(column),
+        // an optional trailing rotation, either `R<digit>` (e.g. `T0R1`)
+        // or a bare digit straight after the column (e.g. `T01`). absent
+        // entirely, the piece keeps its unrotated orientation so the old
+        // single-orientation syntax keeps working.
+        let rot = match chars.next() {
+            Some('R') => chars
+                .next()
+                .and_then(|c| c.to_digit(10))
+                .map(|i| i as u8)
+                .ok_or("bad rotation")?,
+            Some(c) => c.to_digit(10).map(|i| i as u8).ok_or("bad rotation")?,
+            None => 0,
+        };
+
+        let cleared = match tetromino {
+            'Q' => grid.place::<Q>(column, rot),
+            'Z' => grid.place::<Z>(column, rot),
+            'S' => grid.place::<S>(column, rot),
+            'T' => grid.place::<T>(column, rot),
+            'I' => grid.place::<I>(column, rot),
+            'L' => grid.place::<L>(column, rot),
+            'J' => grid.place::<J>(column, rot),
             _ => Err("bad tetromino"),
         }?;
+
+        lines_cleared += cleared;
+        pieces_placed += 1;
+        max_height_seen = std::cmp::max(max_height_seen, grid.stack_height());
+
+        if verbose {
+            println!("{}{}", tetromino, column);
+            println!("{}", grid);
+        }
     }
 
-    Ok(grid.height())
+    Ok(SolveReport {
+        final_height: grid.stack_height(),
+        lines_cleared,
+        max_height_seen,
+        pieces_placed,
+    })
+}
+
+/// How many boards the auto-placement search keeps after scoring each
+/// piece's candidates, before moving on to the next piece.
+const BEAM_WIDTH: usize = 5;
+
+/// Runs each board in `beam` through every legal `(column, rotation)` drop
+/// of `tetromino`, returning the scored results as new boards.
+fn candidates_for(beam: &[Grid], tetromino: char) -> Result<Vec<(i64, Grid)>, &'static str> {
+    let mut candidates = Vec::new();
+
+    for grid in beam {
+        let drops = match tetromino {
+            'Q' => grid.legal_drops::<Q>(),
+            'Z' => grid.legal_drops::<Z>(),
+            'S' => grid.legal_drops::<S>(),
+            'T' => grid.legal_drops::<T>(),
+            'I' => grid.legal_drops::<I>(),
+            'L' => grid.legal_drops::<L>(),
+            'J' => grid.legal_drops::<J>(),
+            _ => return Err("bad tetromino"),
+        };
+
+        for (column, rot) in drops {
+            let mut next = grid.clone();
+
+            let placed = match tetromino {
+                'Q' => next.place::<Q>(column, rot),
+                'Z' => next.place::<Z>(column, rot),
+                'S' => next.place::<S>(column, rot),
+                'T' => next.place::<T>(column, rot),
+                'I' => next.place::<I>(column, rot),
+                'L' => next.place::<L>(column, rot),
+                'J' => next.place::<J>(column, rot),
+                _ => return Err("bad tetromino"),
+            };
+
+            if placed.is_ok() {
+                candidates.push((next.score(), next));
+            }
+        }
+    }
+
+    Ok(candidates)
+}
+
+/// An auto-placement mode: `input` lists only piece types (e.g.
+/// `Q,I,T,L`), and for each one the engine searches every legal
+/// `(column, rotation)` drop, scoring the resulting board with
+/// `Grid::score`, and keeps the `BEAM_WIDTH` best boards as a beam before
+/// advancing to the next piece. Returns the final height of the
+/// best-scoring board.
+fn auto_place(input: &str) -> Result<usize, &'static str> {
+    let mut beam = vec![Grid::new(GRID_WIDTH, GRID_HEIGHT)];
+
+    for entry in input.split(',') {
+        let tetromino = entry.trim().chars().next().ok_or("missing tetromino")?;
+
+        let mut candidates = candidates_for(&beam, tetromino)?;
+        if candidates.is_empty() {
+            return Err("no legal placement");
+        }
+
+        candidates.sort_by_key(|candidate| std::cmp::Reverse(candidate.0));
+        candidates.truncate(BEAM_WIDTH);
+        beam = candidates.into_iter().map(|(_, grid)| grid).collect();
+    }
+
+    Ok(beam
+        .iter()
+        .max_by_key(|grid| grid.score())
+        .map(|grid| grid.stack_height())
+        .unwrap_or(0))
+}
+
+/// An input line is auto-placement mode rather than a replay if every
+/// entry is a bare piece letter with no column (and no rotation).
+fn is_auto_placement(input: &str) -> bool {
+    input
+        .trim()
+        .split(',')
+        .all(|entry| entry.trim().chars().count() == 1)
 }
 
 fn main() -> Result<(), &'static str> {
@@ -324,7 +588,19 @@ fn main() -> Result<(), &'static str> {
         let mut input = String::new();
         match stdin.read_line(&mut input) {
             Ok(0 | 1) => break Ok(()),
-            Ok(_) => println!("{}", solve(&input)?),
+            Ok(_) if is_auto_placement(&input) => println!("{}", auto_place(&input)?),
+            Ok(_) => {
+                let report = solve_report(&input)?;
+
+                if verbose_mode() {
+                    println!(
+                        "cleared {} lines over {} pieces, peak height {}",
+                        report.lines_cleared, report.pieces_placed, report.max_height_seen
+                    );
+                }
+
+                println!("{}", report.final_height);
+            }
             Err(_) => break Err("crash on fatal error"),
         }
     }
@@ -334,6 +610,12 @@ fn main() -> Result<(), &'static str> {
 mod tests {
     use super::*;
 
+    /// Thin wrapper over `solve_report` preserving the height-only return
+    /// value the test suite was originally written against.
+    fn solve(input: &str) -> Result<usize, &'static str> {
+        super::solve_report(input).map(|report| report.final_height)
+    }
+
     #[test]
     fn examples() -> Result<(), &'static str> {
         assert_eq!(2, solve("Q0")?);
@@ -361,6 +643,82 @@ mod tests {
         Ok(())
     }
 
+    // check that rotated pieces are accepted in both the `R<digit>` and
+    // bare-digit forms, and that the rotated shapes actually line up to
+    // clear rows the unrotated orientation couldn't.
+    #[test]
+    fn rotations() -> Result<(), &'static str> {
+        assert_eq!(
+            0,
+            solve("I0R1,I1R1,I2R1,I3R1,I4R1,I5R1,I6R1,I7R1,I8R1,I9R1")?
+        );
+        assert_eq!(0, solve("I01,I11,I21,I31,I41,I51,I61,I71,I81,I91")?);
+        assert_eq!(0, solve("Q0R3,Q2R1,Q4R2,Q6R1,Q8R3")?);
+
+        Ok(())
+    }
+
+    // check that a Grid isn't tied to the 10x100 default, and that
+    // line-clearing still works on a narrower well.
+    #[test]
+    fn narrow_grid() {
+        let mut grid = Grid::new(4, 50);
+        assert_eq!(4, grid.width());
+        assert_eq!(50, grid.height());
+
+        grid.place::<Q>(0, 0).unwrap();
+        grid.place::<Q>(2, 0).unwrap();
+
+        assert_eq!(0, grid.stack_height());
+    }
+
+    // check the rendered ASCII well: occupied squares as `#`, empty
+    // squares as a space, top row first, with a floor underneath.
+    #[test]
+    fn display() {
+        let mut grid = Grid::new(4, 50);
+        grid.place::<Q>(0, 0).unwrap();
+        grid.place::<L>(2, 0).unwrap();
+
+        assert_eq!("  # \n### \n└────┘", grid.to_string());
+    }
+
+    // Q only has one rotation state and is 2 wide, so on a 10-wide empty
+    // board it should have exactly 9 legal columns to drop into.
+    #[test]
+    fn legal_drops() {
+        let grid = Grid::new(GRID_WIDTH, GRID_HEIGHT);
+        assert_eq!(9, grid.legal_drops::<Q>().len());
+    }
+
+    // check that input made up of bare piece letters is routed through
+    // the auto-placement search, and that it can find the zero-height
+    // packing a greedy "always drop at column 0" strategy would miss.
+    #[test]
+    fn auto_placement() -> Result<(), &'static str> {
+        assert!(is_auto_placement("Q,I,T,L,J,S,Z"));
+        assert!(!is_auto_placement("Q0,I2"));
+
+        assert_eq!(0, auto_place("Q,Q,Q,Q,Q")?);
+
+        Ok(())
+    }
+
+    // check that SolveReport tracks more than just the final height: the
+    // total lines cleared across the whole sequence, the tallest the
+    // stack ever got, and how many pieces were placed.
+    #[test]
+    fn solve_report() -> Result<(), &'static str> {
+        let report = super::solve_report("T0,T3,I6,I6")?;
+
+        assert_eq!(1, report.final_height);
+        assert_eq!(1, report.lines_cleared);
+        assert_eq!(2, report.max_height_seen);
+        assert_eq!(4, report.pieces_placed);
+
+        Ok(())
+    }
+
     // check to make sure once a row is cleared, next tetromino can fill
     // in the previously unreachable hole.
     //